@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use fuel_core_compression::{
+    decompress::decompress,
+    ports::TemporalRegistry,
+    RegistryKey,
+    VersionedCompressedBlock,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+        ContractId,
+        Transaction,
+    },
+    fuel_types::canonical::Deserialize,
+};
+
+/// An error occurring while reconstructing a full block from DA-compressed bytes.
+#[derive(Debug)]
+pub enum DaDecompressionError {
+    /// The compressed block bytes could not be canonically decoded.
+    Decode(fuel_core_types::fuel_types::canonical::Error),
+    /// The decoder could not resolve the compressed block against the registry.
+    Decompress(anyhow::Error),
+}
+
+impl std::fmt::Display for DaDecompressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Decode(err) => write!(f, "failed to decode compressed block bytes: {err:?}"),
+            Self::Decompress(err) => write!(f, "failed to decompress block against registry: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for DaDecompressionError {}
+
+/// An in-memory registry of previously-seen compression keys.
+///
+/// DA-compressed blocks reference addresses, asset ids, contract ids, scripts,
+/// predicates and witnesses by [`RegistryKey`] rather than by value, so the
+/// decoder needs to remember every value it has seen in order to resolve the
+/// keys used by later blocks. Writing to a key that already holds a value
+/// replaces that value.
+#[derive(Default)]
+pub struct DaCompressionRegistry {
+    addresses: HashMap<RegistryKey, Address>,
+    asset_ids: HashMap<RegistryKey, AssetId>,
+    contract_ids: HashMap<RegistryKey, ContractId>,
+    scripts: HashMap<RegistryKey, Vec<u8>>,
+    predicates: HashMap<RegistryKey, Vec<u8>>,
+    witnesses: HashMap<RegistryKey, Vec<u8>>,
+}
+
+impl DaCompressionRegistry {
+    /// Creates an empty registry with no previously-seen keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TemporalRegistry for DaCompressionRegistry {
+    fn read_address(&self, key: &RegistryKey) -> anyhow::Result<Address> {
+        self.addresses
+            .get(key)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("unknown address key {key:?}"))
+    }
+
+    fn write_address(&mut self, key: RegistryKey, value: Address) {
+        self.addresses.insert(key, value);
+    }
+
+    fn read_asset_id(&self, key: &RegistryKey) -> anyhow::Result<AssetId> {
+        self.asset_ids
+            .get(key)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("unknown asset id key {key:?}"))
+    }
+
+    fn write_asset_id(&mut self, key: RegistryKey, value: AssetId) {
+        self.asset_ids.insert(key, value);
+    }
+
+    fn read_contract_id(&self, key: &RegistryKey) -> anyhow::Result<ContractId> {
+        self.contract_ids
+            .get(key)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("unknown contract id key {key:?}"))
+    }
+
+    fn write_contract_id(&mut self, key: RegistryKey, value: ContractId) {
+        self.contract_ids.insert(key, value);
+    }
+
+    fn read_script_code(&self, key: &RegistryKey) -> anyhow::Result<Vec<u8>> {
+        self.scripts
+            .get(key)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("unknown script key {key:?}"))
+    }
+
+    fn write_script_code(&mut self, key: RegistryKey, value: Vec<u8>) {
+        self.scripts.insert(key, value);
+    }
+
+    fn read_predicate_code(&self, key: &RegistryKey) -> anyhow::Result<Vec<u8>> {
+        self.predicates
+            .get(key)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("unknown predicate key {key:?}"))
+    }
+
+    fn write_predicate_code(&mut self, key: RegistryKey, value: Vec<u8>) {
+        self.predicates.insert(key, value);
+    }
+
+    fn read_witness(&self, key: &RegistryKey) -> anyhow::Result<Vec<u8>> {
+        self.witnesses
+            .get(key)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("unknown witness key {key:?}"))
+    }
+
+    fn write_witness(&mut self, key: RegistryKey, value: Vec<u8>) {
+        self.witnesses.insert(key, value);
+    }
+}
+
+/// Reconstructs the full set of transactions for a DA-compressed block.
+///
+/// `compressed` is the raw bytes of a [`DaCompressedBlock`](fuel_core_client::client::schema::da_compressed::DaCompressedBlock),
+/// decoded against `registry`. Any new addresses, asset ids, contract ids, scripts,
+/// predicates or witnesses introduced by this block are recorded into `registry` so
+/// that later, higher blocks can resolve the keys they reference.
+pub fn decode_da_compressed_block(
+    registry: &mut DaCompressionRegistry,
+    compressed: &[u8],
+) -> Result<Vec<Transaction>, DaDecompressionError> {
+    let versioned =
+        VersionedCompressedBlock::from_bytes(compressed).map_err(DaDecompressionError::Decode)?;
+    let block = decompress(registry, versioned).map_err(DaDecompressionError::Decompress)?;
+    Ok(block.transactions().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exercises the registry-threading invariant `decode_da_compressed_block` relies on
+    // directly against `TemporalRegistry`, since there's no way to synthesize a real
+    // `VersionedCompressedBlock` payload in a unit test.
+    #[test]
+    fn registry_resolves_a_key_written_by_an_earlier_block() {
+        let mut registry = DaCompressionRegistry::new();
+        let key = RegistryKey::try_from(0u32).expect("0 is a valid registry key");
+        let address = Address::from([7u8; 32]);
+
+        assert!(
+            registry.read_address(&key).is_err(),
+            "a key nobody has written yet must not resolve"
+        );
+
+        // Height N introduces a new address and records it under `key`...
+        registry.write_address(key, address);
+
+        // ...so height N + 1 can resolve the same key without the value being
+        // retransmitted.
+        let resolved = registry
+            .read_address(&key)
+            .expect("a later block should resolve a key written by an earlier one");
+        assert_eq!(resolved, address);
+
+        let unwritten_key = RegistryKey::try_from(1u32).expect("1 is a valid registry key");
+        assert!(registry.read_address(&unwritten_key).is_err());
+    }
+}
@@ -8,10 +8,9 @@ use fuel_core_client::client::{
         block::{
             BlockByHeightArgs,
             Consensus,
-            Header,
+            HeaderVersion,
         },
         primitives::TransactionId,
-        schema,
         tx::TransactionStatus,
         BlockId,
         ConnectionArgs,
@@ -24,6 +23,27 @@ use fuel_core_client::client::schema::block::Block;
 use fuel_core_client::client::schema::da_compressed::DaCompressedBlock;
 use fuel_core_client::client::schema::U32;
 use fuel_core_types::fuel_crypto::PublicKey;
+use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::fuel_types::canonical::Deserialize;
+use serde::de::DeserializeOwned;
+
+/// The `schema` marker module cynic's derive macros resolve types against, re-exported
+/// so downstream crates can define their own [`cynic::QueryFragment`]s against the same
+/// schema this crate builds in `build.rs` and run them through [`ClientExt::run_query`].
+pub use fuel_core_client::client::schema::schema;
+
+/// Path to the GraphQL schema SDL generated by this crate's `build.rs`.
+pub const SCHEMA_SDL_PATH: &str = "./target/schema.sdl";
+
+mod da_compression;
+pub use da_compression::{
+    decode_da_compressed_block,
+    DaCompressionRegistry,
+    DaDecompressionError,
+};
+
+mod subscription;
+pub use subscription::FullBlockStream;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
@@ -61,29 +81,83 @@ pub struct FullBlockByHeightQuery {
     pub block: Option<FullBlock>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./target/schema.sdl", graphql_type = "Header")]
+pub struct FullBlockHeader {
+    pub id: BlockId,
+    pub height: U32,
+    pub version: HeaderVersion,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./target/schema.sdl", graphql_type = "Block")]
 pub struct FullBlock {
     pub id: BlockId,
-    pub header: Header,
+    pub header: FullBlockHeader,
     pub consensus: Consensus,
     pub transactions: Vec<OpaqueTransaction>,
 }
 
+/// An error occurring while recovering a [`FullBlock`]'s producer public key.
+#[derive(Debug)]
+pub enum BlockProducerError {
+    /// The consensus type for this block is not recognized by this client.
+    UnknownConsensus,
+    /// The block's header uses a version this client does not know how to verify.
+    UnsupportedHeaderVersion(HeaderVersion),
+    /// The PoA signature did not recover to a valid public key.
+    InvalidSignature,
+}
+
+impl std::fmt::Display for BlockProducerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownConsensus => write!(f, "unknown consensus type"),
+            Self::UnsupportedHeaderVersion(version) => {
+                write!(f, "unsupported header version: {version:?}")
+            }
+            Self::InvalidSignature => write!(f, "PoA signature did not recover to a public key"),
+        }
+    }
+}
+
+impl std::error::Error for BlockProducerError {}
+
+/// Checks that `version` is a header version this client knows how to construct a
+/// signing message for, split out from [`FullBlock::block_producer`] so it can be
+/// exercised without a live block.
+fn ensure_supported_header_version(version: &HeaderVersion) -> Result<(), BlockProducerError> {
+    match version {
+        HeaderVersion::V1 => Ok(()),
+        other => Err(BlockProducerError::UnsupportedHeaderVersion(other.clone())),
+    }
+}
+
 impl FullBlock {
-    /// Returns the block producer public key, if any.
-    pub fn block_producer(&self) -> Option<PublicKey> {
+    /// Returns the block producer public key, verifying the PoA signature against the
+    /// header-id-to-message construction for this block's header version.
+    pub fn block_producer(&self) -> Result<PublicKey, BlockProducerError> {
+        ensure_supported_header_version(&self.header.version)?;
         let message = self.header.id.clone().into_message();
         match &self.consensus {
-            Consensus::Genesis(_) => Some(Default::default()),
+            Consensus::Genesis(_) => Ok(Default::default()),
             Consensus::PoAConsensus(poa) => {
                 let signature = poa.signature.clone().into_signature();
-                let producer_pub_key = signature.recover(&message);
-                producer_pub_key.ok()
+                signature
+                    .recover(&message)
+                    .map_err(|_| BlockProducerError::InvalidSignature)
             }
-            Consensus::Unknown => None,
+            Consensus::Unknown => Err(BlockProducerError::UnknownConsensus),
         }
     }
+
+    /// Decodes every transaction in this block, pairing each with its [`TransactionId`].
+    pub fn decoded_transactions(&self) -> Result<Vec<(TransactionId, Transaction)>, ConversionError> {
+        self.transactions
+            .iter()
+            .map(|tx| tx.decode().map(|decoded| (tx.id.clone(), decoded)))
+            .collect()
+    }
 }
 
 impl From<FullBlockConnection> for PaginatedResult<FullBlock, String> {
@@ -130,6 +204,92 @@ pub struct DaCompressedBlockWithBlockId {
     pub block: Block,
 }
 
+/// A DA-compressed block reconstructed into its header/consensus metadata and the full
+/// set of decoded transactions it contained.
+pub struct DaCompressedFullBlock {
+    pub block: Block,
+    pub transactions: Vec<Transaction>,
+}
+
+// `DaCompressedBlock` only carries `bytes` (see `DaCompressedBlockWithBlockIdByHeightQuery`
+// above), so there's no single field to page over that nests the matching `Block`.
+// Mirror the by-height query's side-by-side join instead: page the two connections with
+// identical arguments and pair up edges positionally, the same way the by-height query
+// runs `da_compressed_block` and `block` as two separate root fields for the same height.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./target/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct FullDaCompressedBlocksQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub da_compressed_blocks: DaCompressedBlockConnection,
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub blocks: BlockOnlyConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./target/schema.sdl",
+    graphql_type = "DaCompressedBlockConnection"
+)]
+pub struct DaCompressedBlockConnection {
+    pub edges: Vec<DaCompressedBlockEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./target/schema.sdl", graphql_type = "DaCompressedBlockEdge")]
+pub struct DaCompressedBlockEdge {
+    pub node: DaCompressedBlock,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./target/schema.sdl", graphql_type = "BlockConnection")]
+pub struct BlockOnlyConnection {
+    pub edges: Vec<BlockOnlyEdge>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./target/schema.sdl", graphql_type = "BlockEdge")]
+pub struct BlockOnlyEdge {
+    pub node: Block,
+}
+
+impl From<FullDaCompressedBlocksQuery> for PaginatedResult<DaCompressedBlockWithBlockId, String> {
+    fn from(query: FullDaCompressedBlocksQuery) -> Self {
+        let page_info = query.da_compressed_blocks.page_info;
+        let results = query
+            .da_compressed_blocks
+            .edges
+            .into_iter()
+            .zip(query.blocks.edges)
+            .map(|(da_compressed_edge, block_edge)| DaCompressedBlockWithBlockId {
+                da_compressed_block: da_compressed_edge.node,
+                block: block_edge.node,
+            })
+            .collect();
+
+        PaginatedResult {
+            cursor: page_info.end_cursor,
+            has_next_page: page_info.has_next_page,
+            has_previous_page: page_info.has_previous_page,
+            results,
+        }
+    }
+}
+
+impl From<DaCompressedBlockConnection> for PaginatedResult<DaCompressedBlockWithBlockId, String> {
+    fn from(conn: DaCompressedBlockConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./target/schema.sdl", graphql_type = "Transaction")]
@@ -139,6 +299,25 @@ pub struct OpaqueTransaction {
     pub status: Option<TransactionStatus>,
 }
 
+/// An error occurring while decoding a [`HexString`] payload into a typed value.
+#[derive(Debug)]
+pub struct ConversionError(fuel_core_types::fuel_types::canonical::Error);
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to decode raw transaction payload: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl OpaqueTransaction {
+    /// Decodes `raw_payload` into a typed [`Transaction`] using canonical deserialization.
+    pub fn decode(&self) -> Result<Transaction, ConversionError> {
+        Transaction::from_bytes(self.raw_payload.0 .0.as_slice()).map_err(ConversionError)
+    }
+}
+
 #[async_trait::async_trait]
 pub trait ClientExt {
     async fn full_blocks(
@@ -150,6 +329,32 @@ pub trait ClientExt {
         &self,
         height: u32,
     ) -> std::io::Result<Option<DaCompressedBlockWithBlockId>>;
+
+    /// Fetches the DA-compressed block at `height` and reconstructs it, along with its
+    /// full set of transactions, against `registry`, recording any new registry entries
+    /// the block introduces. Blocks must be decoded in increasing height order for
+    /// `registry` to resolve their registry-keyed references.
+    async fn da_compressed_full_block(
+        &self,
+        height: u32,
+        registry: &mut DaCompressionRegistry,
+    ) -> std::io::Result<Option<DaCompressedFullBlock>>;
+
+    /// Streams newly produced full blocks starting at `from_height`, backfilling via
+    /// pagination and reconnecting the underlying subscription as needed.
+    fn subscribe_full_blocks(&self, from_height: u32) -> FullBlockStream<'_>;
+
+    async fn da_compressed_blocks(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> std::io::Result<PaginatedResult<DaCompressedBlockWithBlockId, String>>;
+
+    /// Escape hatch for running a caller-defined query against the same schema this
+    /// crate builds in `build.rs`, for queries not already covered by this trait.
+    async fn run_query<Q, Vars>(&self, vars: Vars) -> std::io::Result<Q>
+    where
+        Q: QueryBuilder<Vars> + DeserializeOwned + Send + 'static,
+        Vars: cynic::QueryVariables + Send + 'static;
 }
 
 #[async_trait::async_trait]
@@ -184,12 +389,56 @@ impl ClientExt for FuelClient {
             Ok(None)
         }
     }
+
+    async fn da_compressed_full_block(
+        &self,
+        height: u32,
+        registry: &mut DaCompressionRegistry,
+    ) -> std::io::Result<Option<DaCompressedFullBlock>> {
+        let Some(with_id) = self.da_compressed_block_with_id(height).await? else {
+            return Ok(None);
+        };
+
+        let transactions = decode_da_compressed_block(
+            registry,
+            with_id.da_compressed_block.bytes.0 .0.as_slice(),
+        )
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        Ok(Some(DaCompressedFullBlock {
+            block: with_id.block,
+            transactions,
+        }))
+    }
+
+    fn subscribe_full_blocks(&self, from_height: u32) -> FullBlockStream<'_> {
+        Box::pin(subscription::full_block_stream(self.clone(), from_height))
+    }
+
+    async fn da_compressed_blocks(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> std::io::Result<PaginatedResult<DaCompressedBlockWithBlockId, String>> {
+        let query = FullDaCompressedBlocksQuery::build(request.into());
+        let blocks = self.query(query).await?.into();
+        Ok(blocks)
+    }
+
+    async fn run_query<Q, Vars>(&self, vars: Vars) -> std::io::Result<Q>
+    where
+        Q: QueryBuilder<Vars> + DeserializeOwned + Send + 'static,
+        Vars: cynic::QueryVariables + Send + 'static,
+    {
+        let query = Q::build(vars);
+        self.query(query).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use fuel_core_client::client::pagination::PageDirection;
+    use futures::StreamExt;
 
     #[tokio::test]
     async fn testnet_works() {
@@ -206,6 +455,29 @@ mod tests {
         assert!(full_block.is_ok(), "{full_block:?}");
     }
 
+    #[tokio::test]
+    async fn can_decode_transactions_in_full_block() {
+        let client = FuelClient::new("https://testnet.fuel.network")
+            .expect("Should connect to the beta 5 network");
+
+        let request = PaginationRequest {
+            cursor: None,
+            results: 1,
+            direction: PageDirection::Backward,
+        };
+        let full_block = client
+            .full_blocks(request)
+            .await
+            .expect("Should fetch the latest block")
+            .results
+            .pop()
+            .expect("Should have at least one block");
+
+        let decoded = full_block.decoded_transactions();
+
+        assert!(decoded.is_ok(), "{decoded:?}");
+    }
+
     #[tokio::test]
     async fn can_get_da_compressed_block() {
         let client = FuelClient::new("https://testnet.fuel.network")
@@ -215,4 +487,63 @@ mod tests {
 
         assert!(da_compressed_block.is_none());
     }
+
+    #[tokio::test]
+    async fn can_paginate_da_compressed_blocks() {
+        let client = FuelClient::new("https://testnet.fuel.network")
+            .expect("Should connect to the testnet");
+
+        let request = PaginationRequest {
+            cursor: None,
+            results: 1,
+            direction: PageDirection::Backward,
+        };
+        let page = client.da_compressed_blocks(request).await;
+
+        assert!(page.is_ok(), "{page:?}");
+    }
+
+    #[tokio::test]
+    async fn subscribe_full_blocks_is_gap_free_across_backfill() {
+        let client = FuelClient::new("https://testnet.fuel.network")
+            .expect("Should connect to the testnet");
+
+        let heights: Vec<u32> = client
+            .subscribe_full_blocks(0)
+            .take(5)
+            .map(|block| block.expect("block should decode").header.height.0)
+            .collect()
+            .await;
+
+        for pair in heights.windows(2) {
+            assert_eq!(pair[1], pair[0] + 1, "{heights:?}");
+        }
+    }
+
+    #[test]
+    fn block_producer_rejects_unsupported_header_versions() {
+        assert!(ensure_supported_header_version(&HeaderVersion::V1).is_ok());
+        assert!(matches!(
+            ensure_supported_header_version(&HeaderVersion::Unknown),
+            Err(BlockProducerError::UnsupportedHeaderVersion(HeaderVersion::Unknown))
+        ));
+    }
+
+    #[tokio::test]
+    async fn run_query_executes_caller_defined_queries() {
+        let client = FuelClient::new("https://testnet.fuel.network")
+            .expect("Should connect to the testnet");
+
+        let request = PaginationRequest {
+            cursor: None,
+            results: 1,
+            direction: PageDirection::Backward,
+        };
+
+        let result = client
+            .run_query::<FullBlocksQuery, _>(request.into())
+            .await;
+
+        assert!(result.is_ok(), "{result:?}");
+    }
 }
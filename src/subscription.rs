@@ -0,0 +1,141 @@
+use std::{
+    io,
+    pin::Pin,
+    time::Duration,
+};
+
+use async_stream::try_stream;
+use cynic::QueryBuilder;
+use futures::{
+    Stream,
+    StreamExt,
+};
+
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    schema::U32,
+    FuelClient,
+};
+
+use crate::FullBlock;
+
+/// A stream of [`FullBlock`]s produced by [`ClientExt::subscribe_full_blocks`](crate::ClientExt::subscribe_full_blocks).
+pub type FullBlockStream<'a> = Pin<Box<dyn Stream<Item = io::Result<FullBlock>> + Send + 'a>>;
+
+/// How long to wait before retrying the subscription after it fails to connect, so a
+/// persistently unreachable node doesn't spin the reconnect loop.
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NewFullBlockSubscriptionArgs {
+    height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./target/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewFullBlockSubscriptionArgs"
+)]
+pub struct NewFullBlockSubscription {
+    #[arguments(height: $height)]
+    pub new_full_block: FullBlock,
+}
+
+/// Relay-style `after` cursors are exclusive of the edge they name, so to fetch a page
+/// that *includes* `next_height` the cursor must name the block directly before it.
+/// There is no cursor before the genesis block, so `next_height == 0` fetches from the
+/// start of the chain.
+fn after_cursor(next_height: u32) -> Option<String> {
+    next_height.checked_sub(1).map(|height| height.to_string())
+}
+
+/// Backfills one page of blocks starting at and including `next_height`, returning the
+/// page's blocks and whether a further page follows. Retries with [`RECONNECT_BACKOFF`] on transport errors
+/// instead of surfacing them, since a transient blip here is just as likely as one on
+/// the subscription itself and should not end the stream.
+async fn backfill(client: &FuelClient, next_height: u32) -> (Vec<FullBlock>, bool) {
+    loop {
+        let request = PaginationRequest {
+            cursor: after_cursor(next_height),
+            results: 100,
+            direction: PageDirection::Forward,
+        };
+        match crate::ClientExt::full_blocks(client, request).await {
+            Ok(page) => return (page.results, page.has_next_page),
+            Err(_) => tokio::time::sleep(RECONNECT_BACKOFF).await,
+        }
+    }
+}
+
+/// Streams [`FullBlock`]s starting at `from_height`, reconnecting the underlying
+/// GraphQL subscription on transport errors and backfilling any gap that opened up
+/// while disconnected.
+pub fn full_block_stream(client: FuelClient, from_height: u32) -> impl Stream<Item = io::Result<FullBlock>> {
+    try_stream! {
+        let mut next_height = from_height;
+
+        loop {
+            let (blocks, has_next_page) = backfill(&client, next_height).await;
+            for block in blocks {
+                next_height = block.header.height.0 + 1;
+                yield block;
+            }
+            if !has_next_page {
+                break;
+            }
+        }
+
+        loop {
+            let subscription = NewFullBlockSubscription::build(NewFullBlockSubscriptionArgs {
+                height: Some(next_height.into()),
+            });
+
+            match client.subscribe(subscription).await {
+                Ok(mut events) => {
+                    while let Some(event) = events.next().await {
+                        if let Ok(response) = event {
+                            let block = response.new_full_block;
+                            next_height = block.header.height.0 + 1;
+                            yield block;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                Err(_) => {
+                    tokio::time::sleep(RECONNECT_BACKOFF).await;
+                }
+            }
+
+            // The subscription dropped; backfill whatever was produced while we were
+            // disconnected before attempting to resubscribe.
+            loop {
+                let (blocks, has_next_page) = backfill(&client, next_height).await;
+                for block in blocks {
+                    next_height = block.header.height.0 + 1;
+                    yield block;
+                }
+                if !has_next_page {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::after_cursor;
+
+    #[test]
+    fn after_cursor_includes_the_target_height() {
+        // There is no cursor before genesis, so height 0 must not be skipped.
+        assert_eq!(after_cursor(0), None);
+        assert_eq!(after_cursor(1), Some("0".to_string()));
+        assert_eq!(after_cursor(42), Some("41".to_string()));
+    }
+}